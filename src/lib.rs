@@ -21,6 +21,7 @@ use accelerometer::RawAccelerometer;
 use core::fmt::Debug;
 use embedded_hal;
 use embedded_hal::digital::v2::OutputPin;
+use embedded_hal_async;
 
 const EXPECTED_DEVICE_ID: u8 = 0x3B;
 
@@ -30,6 +31,63 @@ const Y_OUTPUT_REGISTER: u8 = 0x2B;
 const Z_OUTPUT_REGISTER: u8 = 0x2D;
 
 const CONTROL_REGISTER_1: u8 = 0x20;
+const CONTROL_REGISTER_2: u8 = 0x21;
+const CONTROL_REGISTER_3: u8 = 0x22;
+const HP_FILTER_RESET_REGISTER: u8 = 0x23;
+
+// CONTROL_REGISTER_2 bits
+const FILTERED_DATA_SELECTION: u8 = 0x10; // FDS: route filtered data to output
+const HP_ENABLE_FF_WU_2: u8 = 0x08;
+const HP_ENABLE_FF_WU_1: u8 = 0x04;
+
+const FF_WU_CFG_1: u8 = 0x30;
+const FF_WU_SRC_1: u8 = 0x31;
+const FF_WU_THS_1: u8 = 0x32;
+const FF_WU_DURATION_1: u8 = 0x33;
+
+const CLICK_CFG: u8 = 0x38;
+const CLICK_SRC: u8 = 0x39;
+const CLICK_THSY_X: u8 = 0x3B;
+const CLICK_THSZ: u8 = 0x3C;
+const CLICK_TIMELIMIT: u8 = 0x3D;
+const CLICK_LATENCY: u8 = 0x3E;
+const CLICK_WINDOW: u8 = 0x3F;
+
+// FF_WU_CFG_1 bits
+const FF_WU_AOI: u8 = 0x80;
+const FF_WU_LIR: u8 = 0x40;
+const FF_WU_ZHIE: u8 = 0x20;
+const FF_WU_ZLIE: u8 = 0x10;
+const FF_WU_YHIE: u8 = 0x08;
+const FF_WU_YLIE: u8 = 0x04;
+const FF_WU_XHIE: u8 = 0x02;
+const FF_WU_XLIE: u8 = 0x01;
+
+// FF_WU_SRC_1 bits
+const FF_WU_IA: u8 = 0x40;
+const FF_WU_ZH: u8 = 0x20;
+const FF_WU_ZL: u8 = 0x10;
+const FF_WU_YH: u8 = 0x08;
+const FF_WU_YL: u8 = 0x04;
+const FF_WU_XH: u8 = 0x02;
+const FF_WU_XL: u8 = 0x01;
+
+// CLICK_CFG bits (single/double per axis)
+const CLICK_DOUBLE_Z: u8 = 0x20;
+const CLICK_SINGLE_Z: u8 = 0x10;
+const CLICK_DOUBLE_Y: u8 = 0x08;
+const CLICK_SINGLE_Y: u8 = 0x04;
+const CLICK_DOUBLE_X: u8 = 0x02;
+const CLICK_SINGLE_X: u8 = 0x01;
+const CLICK_LIR: u8 = 0x40;
+
+// CLICK_SRC bits
+const CLICK_IA: u8 = 0x40;
+const CLICK_DOUBLE: u8 = 0x20;
+const CLICK_SINGLE: u8 = 0x10;
+const CLICK_Z: u8 = 0x04;
+const CLICK_Y: u8 = 0x02;
+const CLICK_X: u8 = 0x01;
 const DATA_RATE_100_HZ: u8 = 0x00;
 const DATA_RATE_400_HZ: u8 = 0x80;
 const POWER_DOWN_MODE: u8 = 0x00;
@@ -42,7 +100,19 @@ const X_ENABLE: u8 = 0x01;
 
 const READ_FLAG: u8 = 0x80;
 
-const SCALE: f32 = 4.6 / 256.0; // When multiplied by the output give the acceleration in g's
+// Per-digit sensitivity in g's. The full ±range maps across the 8-bit output,
+// so a reading multiplied by the matching constant gives acceleration in g's
+// (≈18 mg/LSB at ±2 g, ≈72 mg/LSB at ±8 g).
+const SCALE_2G: f32 = 4.6 / 256.0;
+const SCALE_8G: f32 = 18.4 / 256.0;
+
+/// Returns the per-digit sensitivity in g's for a given full-scale range.
+fn sensitivity(scale: &Scale) -> f32 {
+    match scale {
+        Scale::PlusMinus2G => SCALE_2G,
+        Scale::PlusMinus8G => SCALE_8G,
+    }
+}
 
 pub enum PowerMode {
     Active,
@@ -59,10 +129,66 @@ pub enum DataRate {
     Rate400Hz,
 }
 
+/// High-pass cutoff selection (the `HP_coeff` bits of CTRL_REG2); lower
+/// settings remove more of the DC/gravity component.
+pub enum HpCutoff {
+    Level0,
+    Level1,
+    Level2,
+    Level3,
+}
+
+/// Configuration of the built-in high-pass filter in CTRL_REG2.
+///
+/// The filter removes the static gravity/DC component before the output
+/// registers and the interrupt generators. Enable [`data_path`](Self::data_path)
+/// to filter the sampled data, and the per-block flags to filter what feeds the
+/// free-fall/wake-up generators.
+pub struct HpFilterConfig {
+    /// Route filtered data to the output registers (FDS).
+    pub data_path: bool,
+    pub free_fall_wake_up_1: bool,
+    pub free_fall_wake_up_2: bool,
+    pub cutoff: HpCutoff,
+}
+
+impl Default for HpFilterConfig {
+    fn default() -> Self {
+        HpFilterConfig {
+            data_path: false,
+            free_fall_wake_up_1: false,
+            free_fall_wake_up_2: false,
+            cutoff: HpCutoff::Level0,
+        }
+    }
+}
+
+impl HpFilterConfig {
+    fn control_byte(&self) -> u8 {
+        let mut byte = match self.cutoff {
+            HpCutoff::Level0 => 0b00,
+            HpCutoff::Level1 => 0b01,
+            HpCutoff::Level2 => 0b10,
+            HpCutoff::Level3 => 0b11,
+        };
+        if self.data_path {
+            byte |= FILTERED_DATA_SELECTION;
+        }
+        if self.free_fall_wake_up_1 {
+            byte |= HP_ENABLE_FF_WU_1;
+        }
+        if self.free_fall_wake_up_2 {
+            byte |= HP_ENABLE_FF_WU_2;
+        }
+        byte
+    }
+}
+
 pub struct Config {
     pub power_mode: PowerMode,
     pub scale: Scale,
     pub data_rate: DataRate,
+    pub hp_filter: HpFilterConfig,
 }
 
 impl Default for Config {
@@ -71,58 +197,587 @@ impl Default for Config {
             power_mode: PowerMode::Active,
             scale: Scale::PlusMinus2G,
             data_rate: DataRate::Rate400Hz,
+            hp_filter: HpFilterConfig::default(),
         }
     }
 }
 
-pub struct Lis302Dl<Spi, CsPin> {
+/// Auto-increment bit for SPI transfers: the bit just below [`READ_FLAG`] in
+/// the command byte.
+const AUTO_INCREMENT_FLAG: u8 = 0x40;
+
+/// Auto-increment bit for I²C transfers: the MSB of the sub-address. Unlike SPI
+/// there is no read flag, so the whole byte is the register index and the
+/// increment bit lives in bit 7.
+const I2C_AUTO_INCREMENT_FLAG: u8 = 0x80;
+
+/// Error returned while constructing or driving the device.
+///
+/// `E` is the underlying bus error — for the blocking SPI transport that is
+/// [`SpiBusError`] carrying the transfer and chip-select failures, for I²C and
+/// for [`Lis302DlAsync`] it is the HAL's own error.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// A transport-level failure on the SPI or I²C bus.
+    Bus(E),
+    /// The WHO_AM_I register did not read [`EXPECTED_DEVICE_ID`].
+    WrongDeviceId(u8),
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(error: E) -> Self {
+        Error::Bus(error)
+    }
+}
+
+/// Combined SPI transfer / chip-select error for [`SpiInterface`].
+#[derive(Debug)]
+pub enum SpiBusError<SpiError, PinError> {
+    Spi(SpiError),
+    Pin(PinError),
+}
+
+/// Register access shared by the SPI and I²C transports.
+///
+/// Each transport frames the same register map differently — SPI toggles a
+/// chip-select line and sets [`READ_FLAG`] on reads, while I²C addresses a
+/// 7-bit slave and sets the sub-address MSB for auto-increment — so the rest of
+/// the driver is written against this trait instead of a concrete bus.
+pub trait BusInterface {
+    type Error;
+
+    fn read_byte(&mut self, address: u8) -> Result<u8, Self::Error>;
+    fn write_byte(&mut self, address: u8, value: u8) -> Result<(), Self::Error>;
+
+    /// Reads consecutive registers starting at `start_address` into `buffer`
+    /// using the chip's auto-increment mode, in a single bus transaction.
+    fn read_bytes(&mut self, start_address: u8, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// 4-wire SPI transport with an explicit chip-select pin.
+pub struct SpiInterface<Spi, CsPin> {
     spi: Spi,
     chip_select: CsPin,
+}
+
+impl<Spi, SpiError, CsPin, PinError> SpiInterface<Spi, CsPin>
+where
+    Spi: embedded_hal::blocking::spi::Transfer<u8, Error = SpiError>
+        + embedded_hal::blocking::spi::Write<u8, Error = SpiError>,
+    CsPin: OutputPin<Error = PinError>,
+{
+    pub fn new(spi: Spi, chip_select: CsPin) -> Self {
+        SpiInterface { spi, chip_select }
+    }
+}
+
+impl<Spi, SpiError, CsPin, PinError> BusInterface for SpiInterface<Spi, CsPin>
+where
+    Spi: embedded_hal::blocking::spi::Transfer<u8, Error = SpiError>
+        + embedded_hal::blocking::spi::Write<u8, Error = SpiError>,
+    CsPin: OutputPin<Error = PinError>,
+{
+    type Error = SpiBusError<SpiError, PinError>;
+
+    fn read_byte(&mut self, address: u8) -> Result<u8, Self::Error> {
+        let mut bytes = [READ_FLAG | address, 0x0];
+        self.chip_select.set_low().map_err(SpiBusError::Pin)?;
+        self.spi.transfer(&mut bytes).map_err(SpiBusError::Spi)?;
+        self.chip_select.set_high().map_err(SpiBusError::Pin)?;
+        Ok(bytes[1])
+    }
+
+    fn write_byte(&mut self, address: u8, value: u8) -> Result<(), Self::Error> {
+        let request = [address, value];
+        self.chip_select.set_low().map_err(SpiBusError::Pin)?;
+        self.spi.write(&request).map_err(SpiBusError::Spi)?;
+        self.chip_select.set_high().map_err(SpiBusError::Pin)?;
+        Ok(())
+    }
+
+    fn read_bytes(&mut self, start_address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        // Keep chip-select low across the command and data phases so the chip
+        // auto-increments through the consecutive registers.
+        let mut command = [READ_FLAG | AUTO_INCREMENT_FLAG | start_address];
+        self.chip_select.set_low().map_err(SpiBusError::Pin)?;
+        self.spi.transfer(&mut command).map_err(SpiBusError::Spi)?;
+        self.spi.transfer(buffer).map_err(SpiBusError::Spi)?;
+        self.chip_select.set_high().map_err(SpiBusError::Pin)?;
+        Ok(())
+    }
+}
+
+/// I²C transport for boards wired to the LIS302DL's two-wire interface.
+pub struct I2cInterface<I2c> {
+    i2c: I2c,
+    address: u8,
+}
+
+impl<I2c, I2cError> I2cInterface<I2c>
+where
+    I2c: embedded_hal::blocking::i2c::Write<Error = I2cError>
+        + embedded_hal::blocking::i2c::WriteRead<Error = I2cError>,
+{
+    pub fn new(i2c: I2c, address: u8) -> Self {
+        I2cInterface { i2c, address }
+    }
+}
+
+impl<I2c, I2cError> BusInterface for I2cInterface<I2c>
+where
+    I2c: embedded_hal::blocking::i2c::Write<Error = I2cError>
+        + embedded_hal::blocking::i2c::WriteRead<Error = I2cError>,
+{
+    type Error = I2cError;
+
+    fn read_byte(&mut self, address: u8) -> Result<u8, Self::Error> {
+        // Setting the MSB of the sub-address enables auto-increment, which is
+        // harmless for a single-byte read and required for burst reads.
+        let mut bytes = [0x0];
+        self.i2c
+            .write_read(self.address, &[I2C_AUTO_INCREMENT_FLAG | address], &mut bytes)?;
+        Ok(bytes[0])
+    }
+
+    fn write_byte(&mut self, address: u8, value: u8) -> Result<(), Self::Error> {
+        self.i2c.write(self.address, &[address, value])
+    }
+
+    fn read_bytes(&mut self, start_address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.i2c
+            .write_read(self.address, &[I2C_AUTO_INCREMENT_FLAG | start_address], buffer)
+    }
+}
+
+/// Which edge of an axis triggers a free-fall/wake-up event.
+pub enum AxisEvent {
+    Disabled,
+    Low,
+    High,
+    Both,
+}
+
+/// Single and/or double click recognition for one axis.
+pub enum ClickMode {
+    Disabled,
+    Single,
+    Double,
+    SingleAndDouble,
+}
+
+/// Event routed to an INT pin through CTRL_REG3. Each variant maps to the 3-bit
+/// field written into bits 2:0 (INT1) or bits 5:3 (INT2) of the register.
+pub enum EventRoute {
+    GroundedOutput,
+    FreeFallWakeUp1,
+    FreeFallWakeUp2,
+    FreeFallWakeUpBoth,
+    DataReady,
+    Click,
+}
+
+/// Builder-style configuration for the motion-event engine.
+///
+/// All generators are disabled by default; enable the ones you need and hand
+/// the result to [`Lis302Dl::set_interrupt_config`]. Thresholds and timings are
+/// in raw register units as described in the datasheet.
+pub struct InterruptConfig {
+    /// AND (`true`) vs OR (`false`) combination of the enabled FF/WU axes.
+    pub ff_wu_and: bool,
+    /// Latch the FF/WU line until [`Lis302Dl::clear_interrupt`] is called.
+    pub ff_wu_latch: bool,
+    pub ff_wu_x: AxisEvent,
+    pub ff_wu_y: AxisEvent,
+    pub ff_wu_z: AxisEvent,
+    pub ff_wu_threshold: u8,
+    pub ff_wu_duration: u8,
+    /// Latch the click line until the source register is read.
+    pub click_latch: bool,
+    pub click_x: ClickMode,
+    pub click_y: ClickMode,
+    pub click_z: ClickMode,
+    pub click_threshold_x: u8,
+    pub click_threshold_y: u8,
+    pub click_threshold_z: u8,
+    pub click_time_limit: u8,
+    pub click_latency: u8,
+    pub click_window: u8,
+    pub int1: EventRoute,
+    pub int2: EventRoute,
+}
+
+impl Default for InterruptConfig {
+    fn default() -> Self {
+        InterruptConfig {
+            ff_wu_and: false,
+            ff_wu_latch: false,
+            ff_wu_x: AxisEvent::Disabled,
+            ff_wu_y: AxisEvent::Disabled,
+            ff_wu_z: AxisEvent::Disabled,
+            ff_wu_threshold: 0,
+            ff_wu_duration: 0,
+            click_latch: false,
+            click_x: ClickMode::Disabled,
+            click_y: ClickMode::Disabled,
+            click_z: ClickMode::Disabled,
+            click_threshold_x: 0,
+            click_threshold_y: 0,
+            click_threshold_z: 0,
+            click_time_limit: 0,
+            click_latency: 0,
+            click_window: 0,
+            int1: EventRoute::GroundedOutput,
+            int2: EventRoute::GroundedOutput,
+        }
+    }
+}
+
+impl InterruptConfig {
+    pub fn new() -> Self {
+        InterruptConfig::default()
+    }
+
+    /// Enables free-fall/wake-up detection on the given axes with a threshold
+    /// and minimum duration. Latching and AND/OR combination are not changed;
+    /// they follow `ff_wu_latch` and `ff_wu_and`.
+    pub fn free_fall_wake_up(
+        mut self,
+        x: AxisEvent,
+        y: AxisEvent,
+        z: AxisEvent,
+        threshold: u8,
+        duration: u8,
+    ) -> Self {
+        self.ff_wu_x = x;
+        self.ff_wu_y = y;
+        self.ff_wu_z = z;
+        self.ff_wu_threshold = threshold;
+        self.ff_wu_duration = duration;
+        self
+    }
+
+    /// Enables click/tap recognition on the given axes with a shared threshold
+    /// and the timing window registers.
+    pub fn click(
+        mut self,
+        x: ClickMode,
+        y: ClickMode,
+        z: ClickMode,
+        threshold: u8,
+        time_limit: u8,
+        latency: u8,
+        window: u8,
+    ) -> Self {
+        self.click_x = x;
+        self.click_y = y;
+        self.click_z = z;
+        self.click_threshold_x = threshold;
+        self.click_threshold_y = threshold;
+        self.click_threshold_z = threshold;
+        self.click_time_limit = time_limit;
+        self.click_latency = latency;
+        self.click_window = window;
+        self
+    }
+
+    /// Routes events to the INT1 and INT2 pins.
+    pub fn route(mut self, int1: EventRoute, int2: EventRoute) -> Self {
+        self.int1 = int1;
+        self.int2 = int2;
+        self
+    }
+
+    fn ff_wu_cfg_byte(&self) -> u8 {
+        let mut byte = 0;
+        if self.ff_wu_and {
+            byte |= FF_WU_AOI;
+        }
+        if self.ff_wu_latch {
+            byte |= FF_WU_LIR;
+        }
+        byte |= axis_event_bits(&self.ff_wu_z, FF_WU_ZHIE, FF_WU_ZLIE);
+        byte |= axis_event_bits(&self.ff_wu_y, FF_WU_YHIE, FF_WU_YLIE);
+        byte |= axis_event_bits(&self.ff_wu_x, FF_WU_XHIE, FF_WU_XLIE);
+        byte
+    }
+
+    fn click_cfg_byte(&self) -> u8 {
+        let mut byte = 0;
+        if self.click_latch {
+            byte |= CLICK_LIR;
+        }
+        byte |= click_mode_bits(&self.click_z, CLICK_SINGLE_Z, CLICK_DOUBLE_Z);
+        byte |= click_mode_bits(&self.click_y, CLICK_SINGLE_Y, CLICK_DOUBLE_Y);
+        byte |= click_mode_bits(&self.click_x, CLICK_SINGLE_X, CLICK_DOUBLE_X);
+        byte
+    }
+
+    fn ctrl_reg3_byte(&self) -> u8 {
+        route_code(&self.int1) | (route_code(&self.int2) << 3)
+    }
+}
+
+fn axis_event_bits(event: &AxisEvent, high: u8, low: u8) -> u8 {
+    match event {
+        AxisEvent::Disabled => 0,
+        AxisEvent::Low => low,
+        AxisEvent::High => high,
+        AxisEvent::Both => high | low,
+    }
+}
+
+fn click_mode_bits(mode: &ClickMode, single: u8, double: u8) -> u8 {
+    match mode {
+        ClickMode::Disabled => 0,
+        ClickMode::Single => single,
+        ClickMode::Double => double,
+        ClickMode::SingleAndDouble => single | double,
+    }
+}
+
+fn route_code(route: &EventRoute) -> u8 {
+    match route {
+        EventRoute::GroundedOutput => 0b000,
+        EventRoute::FreeFallWakeUp1 => 0b001,
+        EventRoute::FreeFallWakeUp2 => 0b010,
+        EventRoute::FreeFallWakeUpBoth => 0b011,
+        EventRoute::DataReady => 0b100,
+        EventRoute::Click => 0b111,
+    }
+}
+
+/// Decoded contents of the free-fall/wake-up and click source registers,
+/// describing which axes and directions fired.
+pub struct InterruptSource {
+    pub active: bool,
+    pub x_high: bool,
+    pub x_low: bool,
+    pub y_high: bool,
+    pub y_low: bool,
+    pub z_high: bool,
+    pub z_low: bool,
+    pub click_active: bool,
+    pub single_click: bool,
+    pub double_click: bool,
+    pub click_x: bool,
+    pub click_y: bool,
+    pub click_z: bool,
+}
+
+pub struct Lis302Dl<Bus> {
+    bus: Bus,
     config: Config,
 }
 
-impl<Spi, SpiError, CsPin, PinError> Lis302Dl<Spi, CsPin>
+impl<Spi, SpiError, CsPin, PinError> Lis302Dl<SpiInterface<Spi, CsPin>>
 where
     Spi: embedded_hal::blocking::spi::Transfer<u8, Error = SpiError>
         + embedded_hal::blocking::spi::Write<u8, Error = SpiError>,
     CsPin: OutputPin<Error = PinError>,
 {
-    pub fn new(spi: Spi, chip_select: CsPin, config: Config) -> Self {
-        let mut lis302dl = Lis302Dl {
-            spi,
-            chip_select,
-            config,
+    pub fn new(
+        spi: Spi,
+        chip_select: CsPin,
+        config: Config,
+    ) -> Result<Self, Error<SpiBusError<SpiError, PinError>>> {
+        Lis302Dl::with_interface(SpiInterface::new(spi, chip_select), config)
+    }
+}
+
+impl<I2c, I2cError> Lis302Dl<I2cInterface<I2c>>
+where
+    I2c: embedded_hal::blocking::i2c::Write<Error = I2cError>
+        + embedded_hal::blocking::i2c::WriteRead<Error = I2cError>,
+{
+    pub fn new_i2c(i2c: I2c, address: u8, config: Config) -> Result<Self, Error<I2cError>> {
+        Lis302Dl::with_interface(I2cInterface::new(i2c, address), config)
+    }
+}
+
+impl<Bus> Lis302Dl<Bus>
+where
+    Bus: BusInterface,
+{
+    /// Constructs the driver over an arbitrary bus, validating the device ID and
+    /// applying the initial configuration.
+    ///
+    /// Returns [`Error::WrongDeviceId`] if WHO_AM_I does not read
+    /// [`EXPECTED_DEVICE_ID`], or [`Error::Bus`] if the transport fails, so a
+    /// miswired bus can be told apart from a wrong or absent chip.
+    pub fn with_interface(bus: Bus, config: Config) -> Result<Self, Error<Bus::Error>> {
+        let mut lis302dl = Lis302Dl { bus, config };
+
+        let device_id = lis302dl.get_device_id()?;
+        if device_id != EXPECTED_DEVICE_ID {
+            return Err(Error::WrongDeviceId(device_id));
+        }
+
+        lis302dl.set_control_register_1()?;
+        lis302dl.set_control_register_2()?;
+
+        Ok(lis302dl)
+    }
+
+    fn read_byte(&mut self, address: u8) -> Result<u8, Bus::Error> {
+        self.bus.read_byte(address)
+    }
+
+    fn write_byte(&mut self, address: u8, value: u8) -> Result<(), Bus::Error> {
+        self.bus.write_byte(address, value)
+    }
+
+    fn get_device_id(&mut self) -> Result<u8, Bus::Error> {
+        self.read_byte(WHO_AM_I_REGISTER)
+    }
+
+    fn set_control_register_1(&mut self) -> Result<(), Bus::Error> {
+        let mut control_byte = X_ENABLE | Y_ENABLE | Z_ENABLE;
+        control_byte |= match self.config.power_mode {
+            PowerMode::Active => ACTIVE_MODE,
+            PowerMode::PowerDown => POWER_DOWN_MODE,
+        };
+        control_byte |= match self.config.scale {
+            Scale::PlusMinus2G => SCALE_PLUS_MINUS_2G,
+            Scale::PlusMinus8G => SCALE_PLUS_MINUS_8G,
         };
+        control_byte |= match self.config.data_rate {
+            DataRate::Rate100Hz => DATA_RATE_100_HZ,
+            DataRate::Rate400Hz => DATA_RATE_400_HZ,
+        };
+        self.write_byte(CONTROL_REGISTER_1, control_byte)
+    }
+
+    fn set_control_register_2(&mut self) -> Result<(), Bus::Error> {
+        self.write_byte(CONTROL_REGISTER_2, self.config.hp_filter.control_byte())
+    }
+
+    /// Updates the full-scale range, rewriting CONTROL_REGISTER_1 so the chip
+    /// and the sensitivity used by `accel_norm` stay in sync.
+    pub fn set_scale(&mut self, scale: Scale) -> Result<(), Bus::Error> {
+        self.config.scale = scale;
+        self.set_control_register_1()
+    }
+
+    /// Updates the high-pass filter configuration, rewriting CONTROL_REGISTER_2.
+    pub fn set_hp_filter(&mut self, hp_filter: HpFilterConfig) -> Result<(), Bus::Error> {
+        self.config.hp_filter = hp_filter;
+        self.set_control_register_2()
+    }
+
+    /// Resets the high-pass filter by dummy-reading the reference register,
+    /// zeroing the stored DC level.
+    pub fn reset_hp_filter(&mut self) -> Result<(), Bus::Error> {
+        self.read_byte(HP_FILTER_RESET_REGISTER)?;
+        Ok(())
+    }
+
+    /// Programs the free-fall/wake-up and click engines and routes their events
+    /// to the INT1/INT2 pins via CTRL_REG3.
+    pub fn set_interrupt_config(&mut self, config: InterruptConfig) -> Result<(), Bus::Error> {
+        self.write_byte(FF_WU_THS_1, config.ff_wu_threshold)?;
+        self.write_byte(FF_WU_DURATION_1, config.ff_wu_duration)?;
+        self.write_byte(FF_WU_CFG_1, config.ff_wu_cfg_byte())?;
+
+        // CLICK_THSY_X packs the Y threshold in the high nibble and X in the low.
+        self.write_byte(
+            CLICK_THSY_X,
+            ((config.click_threshold_y & 0x0F) << 4) | (config.click_threshold_x & 0x0F),
+        )?;
+        self.write_byte(CLICK_THSZ, config.click_threshold_z)?;
+        self.write_byte(CLICK_TIMELIMIT, config.click_time_limit)?;
+        self.write_byte(CLICK_LATENCY, config.click_latency)?;
+        self.write_byte(CLICK_WINDOW, config.click_window)?;
+        self.write_byte(CLICK_CFG, config.click_cfg_byte())?;
+
+        self.write_byte(CONTROL_REGISTER_3, config.ctrl_reg3_byte())
+    }
+
+    /// Reads both source registers to deassert any latched INT lines, returning
+    /// the events that were pending.
+    pub fn clear_interrupt(&mut self) -> Result<InterruptSource, Bus::Error> {
+        self.read_interrupt_source()
+    }
+
+    /// Reads and decodes the free-fall/wake-up and click source registers.
+    ///
+    /// Reading the registers also deasserts the corresponding latched line, so
+    /// this doubles as [`clear_interrupt`](Self::clear_interrupt).
+    pub fn read_interrupt_source(&mut self) -> Result<InterruptSource, Bus::Error> {
+        let ff_wu = self.read_byte(FF_WU_SRC_1)?;
+        let click = self.read_byte(CLICK_SRC)?;
+        Ok(InterruptSource {
+            active: ff_wu & FF_WU_IA != 0,
+            x_high: ff_wu & FF_WU_XH != 0,
+            x_low: ff_wu & FF_WU_XL != 0,
+            y_high: ff_wu & FF_WU_YH != 0,
+            y_low: ff_wu & FF_WU_YL != 0,
+            z_high: ff_wu & FF_WU_ZH != 0,
+            z_low: ff_wu & FF_WU_ZL != 0,
+            click_active: click & CLICK_IA != 0,
+            single_click: click & CLICK_SINGLE != 0,
+            double_click: click & CLICK_DOUBLE != 0,
+            click_x: click & CLICK_X != 0,
+            click_y: click & CLICK_Y != 0,
+            click_z: click & CLICK_Z != 0,
+        })
+    }
+}
+
+/// Non-blocking counterpart of [`Lis302Dl`] built on `embedded-hal-async`.
+///
+/// The register map and framing are identical to the blocking driver; only the
+/// bus access is awaited instead of busy-waited. Chip-select is managed by the
+/// [`SpiDevice`](embedded_hal_async::spi::SpiDevice) implementation, so no
+/// separate CS pin is needed. This lets 400 Hz sampling cooperate with other
+/// tasks on the same core under executors such as embassy.
+pub struct Lis302DlAsync<Spi> {
+    spi: Spi,
+    config: Config,
+}
 
-        if lis302dl.get_device_id() != EXPECTED_DEVICE_ID {
-            // TODO: error
+impl<Spi, SpiError> Lis302DlAsync<Spi>
+where
+    Spi: embedded_hal_async::spi::SpiDevice<u8, Error = SpiError>,
+{
+    pub async fn new(spi: Spi, config: Config) -> Result<Self, Error<SpiError>> {
+        let mut lis302dl = Lis302DlAsync { spi, config };
+
+        let device_id = lis302dl.get_device_id().await?;
+        if device_id != EXPECTED_DEVICE_ID {
+            return Err(Error::WrongDeviceId(device_id));
         }
 
-        lis302dl.set_control_register_1();
+        lis302dl.set_control_register_1().await?;
+        lis302dl.set_control_register_2().await?;
 
-        lis302dl
+        Ok(lis302dl)
     }
 
-    fn read_byte(&mut self, address: u8) -> u8 {
+    async fn read_byte(&mut self, address: u8) -> Result<u8, SpiError> {
         let mut bytes = [READ_FLAG | address, 0x0];
-        self.chip_select.set_low().ok();
-        self.spi.transfer(&mut bytes).ok();
-        self.chip_select.set_high().ok();
-        bytes[1]
+        self.spi.transfer_in_place(&mut bytes).await?;
+        Ok(bytes[1])
     }
 
-    fn write_byte(&mut self, address: u8, value: u8) {
-        let mut request = [address, value];
-        self.chip_select.set_low().ok();
-        self.spi.write(&mut request).ok();
-        self.chip_select.set_high().ok();
+    async fn write_byte(&mut self, address: u8, value: u8) -> Result<(), SpiError> {
+        let request = [address, value];
+        self.spi.write(&request).await
     }
 
-    fn get_device_id(&mut self) -> u8 {
-        self.read_byte(WHO_AM_I_REGISTER)
+    async fn read_bytes<const N: usize>(&mut self, start_address: u8) -> Result<[u8; N], SpiError> {
+        // Prefix the command byte with the read and auto-increment flags so the
+        // chip walks the consecutive registers within a single transfer.
+        let mut bytes = [0u8; N];
+        bytes[0] = READ_FLAG | AUTO_INCREMENT_FLAG | start_address;
+        self.spi.transfer_in_place(&mut bytes).await?;
+        Ok(bytes)
     }
 
-    fn set_control_register_1(&mut self) {
+    async fn get_device_id(&mut self) -> Result<u8, SpiError> {
+        self.read_byte(WHO_AM_I_REGISTER).await
+    }
+
+    async fn set_control_register_1(&mut self) -> Result<(), SpiError> {
         let mut control_byte = X_ENABLE | Y_ENABLE | Z_ENABLE;
         control_byte |= match self.config.power_mode {
             PowerMode::Active => ACTIVE_MODE,
@@ -136,41 +791,70 @@ where
             DataRate::Rate100Hz => DATA_RATE_100_HZ,
             DataRate::Rate400Hz => DATA_RATE_400_HZ,
         };
-        self.write_byte(CONTROL_REGISTER_1, control_byte);
+        self.write_byte(CONTROL_REGISTER_1, control_byte).await
+    }
+
+    async fn set_control_register_2(&mut self) -> Result<(), SpiError> {
+        self.write_byte(CONTROL_REGISTER_2, self.config.hp_filter.control_byte())
+            .await
+    }
+
+    /// Reads the raw X/Y/Z output registers without blocking the executor.
+    pub async fn accel_raw(&mut self) -> Result<accelerometer::vector::I8x3, SpiError> {
+        // The output registers are interleaved with a dummy byte each
+        // (0x29 X, 0x2A, 0x2B Y, 0x2C, 0x2D Z), so one auto-incrementing burst
+        // from X covers all three axes in a single transfer. The leading byte of
+        // the buffer is the command echo, so the data starts at index 1.
+        let bytes = self.read_bytes::<6>(X_OUTPUT_REGISTER).await?;
+        Ok(accelerometer::vector::I8x3::new(
+            i8::from_le_bytes([bytes[1]]),
+            i8::from_le_bytes([bytes[3]]),
+            i8::from_le_bytes([bytes[5]]),
+        ))
+    }
+
+    /// Reads the output registers and scales them to g's.
+    pub async fn accel_norm(&mut self) -> Result<accelerometer::vector::F32x3, SpiError> {
+        let raw_acceleration = self.accel_raw().await?;
+        let scale = sensitivity(&self.config.scale);
+        Ok(accelerometer::vector::F32x3::new(
+            raw_acceleration.x as f32 * scale,
+            raw_acceleration.y as f32 * scale,
+            raw_acceleration.z as f32 * scale,
+        ))
     }
 }
 
-impl<Spi, SpiError, CsPin, PinError> accelerometer::RawAccelerometer<accelerometer::vector::I8x3>
-    for Lis302Dl<Spi, CsPin>
+impl<Bus> accelerometer::RawAccelerometer<accelerometer::vector::I8x3> for Lis302Dl<Bus>
 where
-    Spi: embedded_hal::blocking::spi::Transfer<u8, Error = SpiError>
-        + embedded_hal::blocking::spi::Write<u8, Error = SpiError>,
-    CsPin: OutputPin<Error = PinError>,
-    SpiError: Debug,
+    Bus: BusInterface,
+    Bus::Error: Debug,
 {
-    type Error = SpiError;
+    type Error = Bus::Error;
     fn accel_raw(
         &mut self,
     ) -> Result<accelerometer::vector::I8x3, accelerometer::Error<Self::Error>> {
-        let x = self.read_byte(X_OUTPUT_REGISTER);
-        let y = self.read_byte(Y_OUTPUT_REGISTER);
-        let z = self.read_byte(Z_OUTPUT_REGISTER);
+        // The output registers are interleaved with a dummy byte each
+        // (0x29 X, 0x2A, 0x2B Y, 0x2C, 0x2D Z), so one auto-incrementing burst
+        // from X covers all three axes in a single transaction.
+        let mut buffer = [0u8; 5];
+        self.bus
+            .read_bytes(X_OUTPUT_REGISTER, &mut buffer)
+            .map_err(accelerometer::Error::new)?;
         Ok(accelerometer::vector::I8x3::new(
-            i8::from_le_bytes([x]),
-            i8::from_le_bytes([y]),
-            i8::from_le_bytes([z]),
+            i8::from_le_bytes([buffer[0]]),
+            i8::from_le_bytes([buffer[2]]),
+            i8::from_le_bytes([buffer[4]]),
         ))
     }
 }
 
-impl<Spi, SpiError, CsPin, PinError> accelerometer::Accelerometer for Lis302Dl<Spi, CsPin>
+impl<Bus> accelerometer::Accelerometer for Lis302Dl<Bus>
 where
-    Spi: embedded_hal::blocking::spi::Transfer<u8, Error = SpiError>
-        + embedded_hal::blocking::spi::Write<u8, Error = SpiError>,
-    CsPin: OutputPin<Error = PinError>,
-    SpiError: Debug,
+    Bus: BusInterface,
+    Bus::Error: Debug,
 {
-    type Error = SpiError;
+    type Error = Bus::Error;
     fn sample_rate(&mut self) -> Result<f32, accelerometer::Error<Self::Error>> {
         match self.config.data_rate {
             DataRate::Rate100Hz => Ok(100.0),
@@ -181,11 +865,12 @@ where
     fn accel_norm(
         &mut self,
     ) -> Result<accelerometer::vector::F32x3, accelerometer::Error<Self::Error>> {
-        let raw_acceleration: accelerometer::vector::I8x3 = self.accel_raw().unwrap();
+        let raw_acceleration: accelerometer::vector::I8x3 = self.accel_raw()?;
+        let scale = sensitivity(&self.config.scale);
         Ok(accelerometer::vector::F32x3::new(
-            raw_acceleration.x as f32 * SCALE,
-            raw_acceleration.y as f32 * SCALE,
-            raw_acceleration.z as f32 * SCALE,
+            raw_acceleration.x as f32 * scale,
+            raw_acceleration.y as f32 * scale,
+            raw_acceleration.z as f32 * scale,
         ))
     }
 }